@@ -17,8 +17,8 @@ pub struct OrderBoard {
     pub bids: Books,
 }
 
-#[derive(Debug, Clone)]
-enum Side {
+#[derive(Debug, Clone, Copy)]
+pub enum Side {
     Ask,
     Bid,
 }
@@ -50,6 +50,143 @@ impl OrderBoard {
     pub fn wall(&self, size: Decimal) -> (Option<&Book>, Option<&Book>) {
         (self.asks.wall(size), self.bids.wall(size))
     }
+
+    /// 指定sideをtarget_size分だけ約定させたときの平均執行価格(VWAP)を見積もる
+    pub fn quote(&self, side: Side, size: Decimal) -> FillResult {
+        match side {
+            Side::Ask => self.asks.fill(size),
+            Side::Bid => self.bids.fill(size),
+        }
+    }
+
+    /// 上位depthレベルで正規化した板の厚みの偏り(imbalance)を返す
+    /// (sum_bid_size - sum_ask_size) / (sum_bid_size + sum_ask_size) で値域は`[-1, 1]`。
+    /// bestから内側へ向かって数える。いずれかのsideが空の場合は`None`。
+    pub fn imbalance(&self, depth: usize) -> Option<Decimal> {
+        if self.asks.books.is_empty() || self.bids.books.is_empty() {
+            return None;
+        }
+
+        let bid_size: Decimal = self
+            .bids
+            .books
+            .values()
+            .rev()
+            .take(depth)
+            .map(|book| book.size)
+            .sum();
+        let ask_size: Decimal = self
+            .asks
+            .books
+            .values()
+            .take(depth)
+            .map(|book| book.size)
+            .sum();
+
+        let total = bid_size + ask_size;
+        if total.is_zero() {
+            return None;
+        }
+        Some((bid_size - ask_size) / total)
+    }
+
+    /// 上位depthレベルの累積厚み曲線を`(price, cumulative_size)`で返す
+    /// bestから内側へ向かってaskとbidそれぞれの累積sizeを積み上げる。
+    /// 戻り値は`(asks, bids)`。該当sideが空の場合はそのVecは空。
+    pub fn cumulative_depth(
+        &self,
+        depth: usize,
+    ) -> (Vec<(Decimal, Decimal)>, Vec<(Decimal, Decimal)>) {
+        let mut cum = Decimal::ZERO;
+        let asks = self
+            .asks
+            .books
+            .values()
+            .take(depth)
+            .map(|book| {
+                cum += book.size;
+                (book.price, cum)
+            })
+            .collect();
+
+        let mut cum = Decimal::ZERO;
+        let bids = self
+            .bids
+            .books
+            .values()
+            .rev()
+            .take(depth)
+            .map(|book| {
+                cum += book.size;
+                (book.price, cum)
+            })
+            .collect();
+
+        (asks, bids)
+    }
+
+    /// 板をstruct-of-arrays(列指向)へ平坦化する
+    /// ask -> bidの順に、price / size / digits考慮済み整数price(PriceKey i64) /
+    /// sideマーカーの並列な`Vec`を返す。Arrow風の`RecordBatch`構築などに直接渡せる。
+    pub fn to_columns(&self) -> Columns {
+        let mut columns = self.asks.columns();
+        columns.append(self.bids.columns());
+        columns
+    }
+
+    /// `to_columns`のrayon並列版
+    /// ask列とbid列をそれぞれ並列に構築してから連結する。
+    pub fn to_columns_par(&self) -> Columns {
+        let (mut asks, bids) = rayon::join(|| self.asks.columns(), || self.bids.columns());
+        asks.append(bids);
+        asks
+    }
+}
+
+/// TopOfBook
+/// 板の最良気配(best bid/ask)から導かれる代表的な統計量を提供します。
+/// いずれかのsideが空の場合は`None`を返します。
+/// 精度を落とさないため、計算はすべて`Decimal`演算で行います。
+pub trait TopOfBook {
+    /// 最良買い気配のBook(価格とサイズ)
+    fn best_bid(&self) -> Option<&Book>;
+    /// 最良売り気配のBook(価格とサイズ)
+    fn best_ask(&self) -> Option<&Book>;
+
+    /// 仲値 = (best_ask.price + best_bid.price) / 2
+    fn mid_price(&self) -> Option<Decimal> {
+        let (ask, bid) = (self.best_ask()?, self.best_bid()?);
+        Some((ask.price + bid.price) / Decimal::new(2, 0))
+    }
+
+    /// スプレッド = best_ask.price - best_bid.price
+    fn spread(&self) -> Option<Decimal> {
+        let (ask, bid) = (self.best_ask()?, self.best_bid()?);
+        Some(ask.price - bid.price)
+    }
+
+    /// マイクロプライス
+    /// 各sideの価格を反対側のサイズで加重した推定値:
+    /// (best_bid.price * best_ask.size + best_ask.price * best_bid.size)
+    ///     / (best_bid.size + best_ask.size)
+    fn microprice(&self) -> Option<Decimal> {
+        let (ask, bid) = (self.best_ask()?, self.best_bid()?);
+        let total = bid.size + ask.size;
+        if total.is_zero() {
+            return None;
+        }
+        Some((bid.price * ask.size + ask.price * bid.size) / total)
+    }
+}
+
+impl TopOfBook for OrderBoard {
+    fn best_bid(&self) -> Option<&Book> {
+        self.bids.best()
+    }
+
+    fn best_ask(&self) -> Option<&Book> {
+        self.asks.best()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -146,6 +283,109 @@ impl Books {
             Side::Ask => self.books.values().find(|book| book.size > size),
         }
     }
+
+    /// target_sizeを最良気配から複数レベルに渡って約定させ、平均執行価格を求める
+    /// bestからの執行順(askは昇順、bidは降順)でsizeとprice*sizeを累積し、
+    /// 最終レベルでは必要な分だけを部分約定として取り込む。
+    /// 板を消化し切ってもtarget_sizeに満たない場合は`remaining > 0`で返る。
+    /// 板が空の場合は`avg_price = 0`、`filled = 0`を返す。
+    pub fn fill(&self, target_size: Decimal) -> FillResult {
+        let mut filled = Decimal::ZERO;
+        let mut notional = Decimal::ZERO;
+        let mut levels_consumed = 0usize;
+
+        let books: Box<dyn Iterator<Item = &Book>> = match self.side {
+            Side::Bid => Box::new(self.books.values().rev()),
+            Side::Ask => Box::new(self.books.values()),
+        };
+
+        for book in books {
+            if filled >= target_size {
+                break;
+            }
+            let take = (target_size - filled).min(book.size);
+            filled += take;
+            notional += book.price * take;
+            levels_consumed += 1;
+        }
+
+        let remaining = (target_size - filled).max(Decimal::ZERO);
+        let avg_price = if filled.is_zero() {
+            Decimal::ZERO
+        } else {
+            notional / filled
+        };
+
+        FillResult {
+            filled,
+            remaining,
+            avg_price,
+            levels_consumed,
+        }
+    }
+
+    /// 自身のBookを列指向(`Columns`)へ平坦化する
+    /// keyはdigits考慮済みの整数price(PriceKey i64)をそのまま使う。
+    fn columns(&self) -> Columns {
+        let mut columns = Columns::with_capacity(self.books.len());
+        for (key, book) in self.books.iter() {
+            columns.price.push(book.price);
+            columns.size.push(book.size);
+            columns.key.push(key.0);
+            columns.side.push(self.side);
+        }
+        columns
+    }
+}
+
+/// `Books::fill`の結果
+/// filled: 約定できた数量, remaining: 未約定の数量,
+/// avg_price: 約定分の平均執行価格(VWAP), levels_consumed: 消化したレベル数
+#[derive(Debug, Clone)]
+pub struct FillResult {
+    pub filled: Decimal,
+    pub remaining: Decimal,
+    pub avg_price: Decimal,
+    pub levels_consumed: usize,
+}
+
+/// 板を列指向(struct-of-arrays)で表現する
+/// price / size / digits考慮済み整数price(PriceKey i64) / sideマーカーを
+/// 行ごとに対応させた並列な`Vec`として保持する。
+#[derive(Debug, Clone, Default)]
+pub struct Columns {
+    pub price: Vec<Decimal>,
+    pub size: Vec<Decimal>,
+    pub key: Vec<i64>,
+    pub side: Vec<Side>,
+}
+
+impl Columns {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            price: Vec::with_capacity(capacity),
+            size: Vec::with_capacity(capacity),
+            key: Vec::with_capacity(capacity),
+            side: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// 行数
+    pub fn len(&self) -> usize {
+        self.price.len()
+    }
+
+    /// 空かどうか
+    pub fn is_empty(&self) -> bool {
+        self.price.is_empty()
+    }
+
+    fn append(&mut self, mut other: Columns) {
+        self.price.append(&mut other.price);
+        self.size.append(&mut other.size);
+        self.key.append(&mut other.key);
+        self.side.append(&mut other.side);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -252,6 +492,123 @@ pub fn converter<P: Convertible, S: Convertible>(price: P, size: S) -> Result<Bo
     Ok(Book { price, size })
 }
 
+/// 板の(デ)シリアライズ
+/// `Decimal`をfloatに落とさず、`{ mantissa: i128, scale: u32 }`に分解して
+/// 正確かつ可搬な形で保存・復元する。`serde`featureでのみ有効。
+#[cfg(feature = "serde")]
+mod snapshot {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    /// `Decimal`をmantissaとscaleで正確に表現する(floatを経由しない)
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct DecimalRepr {
+        mantissa: i128,
+        scale: u32,
+    }
+
+    impl DecimalRepr {
+        fn encode(d: Decimal) -> Self {
+            Self {
+                mantissa: d.mantissa(),
+                scale: d.scale(),
+            }
+        }
+
+        fn decode(&self) -> Decimal {
+            Decimal::from_i128_with_scale(self.mantissa, self.scale)
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct BookRepr {
+        price: DecimalRepr,
+        size: DecimalRepr,
+    }
+
+    /// `OrderBoard::new`の状態を再現できるよう side / max_number_of_books / digits を持つ
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct BooksRepr {
+        /// 0 = Ask, 1 = Bid
+        side: u8,
+        max_number_of_books: usize,
+        digits: u64,
+        books: Vec<BookRepr>,
+    }
+
+    impl BooksRepr {
+        fn encode(books: &Books) -> Self {
+            let side = match books.side {
+                Side::Ask => 0,
+                Side::Bid => 1,
+            };
+            Self {
+                side,
+                max_number_of_books: books.max_number_of_books,
+                digits: books.digits,
+                books: books
+                    .books
+                    .values()
+                    .map(|b| BookRepr {
+                        price: DecimalRepr::encode(b.price),
+                        size: DecimalRepr::encode(b.size),
+                    })
+                    .collect(),
+            }
+        }
+
+        fn decode(&self) -> Books {
+            let side = if self.side == 1 { Side::Bid } else { Side::Ask };
+            let mut books = Books::new(side, self.max_number_of_books, self.digits);
+            // keyが一貫して再計算されるよう`push`で組み直す
+            for b in &self.books {
+                books.push(Book {
+                    price: b.price.decode(),
+                    size: b.size.decode(),
+                });
+            }
+            books
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct OrderBoardSnapshot {
+        asks: BooksRepr,
+        bids: BooksRepr,
+    }
+
+    impl OrderBoardSnapshot {
+        fn encode(board: &OrderBoard) -> Self {
+            Self {
+                asks: BooksRepr::encode(&board.asks),
+                bids: BooksRepr::encode(&board.bids),
+            }
+        }
+
+        fn decode(&self) -> OrderBoard {
+            OrderBoard {
+                asks: self.asks.decode(),
+                bids: self.bids.decode(),
+            }
+        }
+    }
+
+    impl OrderBoard {
+        /// 板をmantissa+scale分解でJSONバイト列に書き出す(floatを経由しない)
+        pub fn serialize(&self) -> Result<Vec<u8>, &'static str> {
+            serde_json::to_vec(&OrderBoardSnapshot::encode(self))
+                .map_err(|_| "Failed to serialize OrderBoard")
+        }
+
+        /// `serialize`で書き出したバイト列から板を復元する
+        pub fn deserialize(bytes: &[u8]) -> Result<Self, &'static str> {
+            let snapshot: OrderBoardSnapshot =
+                serde_json::from_slice(bytes).map_err(|_| "Failed to deserialize OrderBoard")?;
+            Ok(snapshot.decode())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,6 +729,167 @@ mod tests {
         }
     }
 
+    // TopOfBookの統計量を検証
+    #[test]
+    fn test_top_of_book() {
+        let mut order_board = OrderBoard::new(10, 2);
+
+        // 空の板ではNoneを返す
+        assert!(order_board.mid_price().is_none());
+        assert!(order_board.spread().is_none());
+        assert!(order_board.microprice().is_none());
+
+        order_board.bids.push(Book {
+            price: Decimal::new(100, 0),
+            size: Decimal::new(2, 0),
+        });
+        order_board.asks.push(Book {
+            price: Decimal::new(102, 0),
+            size: Decimal::new(6, 0),
+        });
+
+        assert_eq!(order_board.mid_price().unwrap(), Decimal::new(101, 0));
+        assert_eq!(order_board.spread().unwrap(), Decimal::new(2, 0));
+        // (100*6 + 102*2) / (2 + 6) = 804 / 8 = 100.5
+        assert_eq!(order_board.microprice().unwrap(), Decimal::new(1005, 1));
+    }
+
+    // 板を横断するfill/quoteの検証
+    #[test]
+    fn test_fill_quote() {
+        let mut order_board = OrderBoard::new(10, 0);
+
+        // ask: 100@1, 101@2, 102@5
+        order_board.asks.extend(vec![
+            Book {
+                price: Decimal::new(100, 0),
+                size: Decimal::new(1, 0),
+            },
+            Book {
+                price: Decimal::new(101, 0),
+                size: Decimal::new(2, 0),
+            },
+            Book {
+                price: Decimal::new(102, 0),
+                size: Decimal::new(5, 0),
+            },
+        ]);
+
+        // 4枚を約定: 100*1 + 101*2 + 102*1 = 404, avg = 101
+        let res = order_board.quote(Side::Ask, Decimal::new(4, 0));
+        assert_eq!(res.filled, Decimal::new(4, 0));
+        assert_eq!(res.remaining, Decimal::ZERO);
+        assert_eq!(res.avg_price, Decimal::new(101, 0));
+        assert_eq!(res.levels_consumed, 3);
+
+        // 板を消化し切っても足りない場合
+        let res = order_board.quote(Side::Ask, Decimal::new(20, 0));
+        assert_eq!(res.filled, Decimal::new(8, 0));
+        assert_eq!(res.remaining, Decimal::new(12, 0));
+
+        // 空のsideは filled=0, avg_price=0
+        let res = order_board.quote(Side::Bid, Decimal::new(1, 0));
+        assert_eq!(res.filled, Decimal::ZERO);
+        assert_eq!(res.avg_price, Decimal::ZERO);
+    }
+
+    // snapshotのroundtripを検証(正確な復元)
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let mut order_board = OrderBoard::new(10, 4);
+        order_board.asks.push(Book {
+            price: Decimal::new(123456, 4),
+            size: Decimal::new(25, 1),
+        });
+        order_board.bids.push(Book {
+            price: Decimal::new(123450, 4),
+            size: Decimal::new(10, 1),
+        });
+
+        let bytes = order_board.serialize().unwrap();
+        let restored = OrderBoard::deserialize(&bytes).unwrap();
+
+        let (ask, bid) = restored.best();
+        assert_eq!(ask.unwrap().price, Decimal::new(123456, 4));
+        assert_eq!(bid.unwrap().price, Decimal::new(123450, 4));
+        assert_eq!(restored.asks.books.len(), 1);
+        assert_eq!(restored.bids.books.len(), 1);
+    }
+
+    // imbalance / cumulative_depthの検証
+    #[test]
+    fn test_imbalance_depth() {
+        let mut order_board = OrderBoard::new(10, 0);
+
+        assert!(order_board.imbalance(3).is_none());
+
+        // bid: 100@3, 99@2  ask: 101@1, 102@4
+        order_board.bids.extend(vec![
+            Book {
+                price: Decimal::new(100, 0),
+                size: Decimal::new(3, 0),
+            },
+            Book {
+                price: Decimal::new(99, 0),
+                size: Decimal::new(2, 0),
+            },
+        ]);
+        order_board.asks.extend(vec![
+            Book {
+                price: Decimal::new(101, 0),
+                size: Decimal::new(1, 0),
+            },
+            Book {
+                price: Decimal::new(102, 0),
+                size: Decimal::new(4, 0),
+            },
+        ]);
+
+        // 上位1レベル: (3 - 1) / (3 + 1) = 0.5
+        assert_eq!(order_board.imbalance(1).unwrap(), Decimal::new(5, 1));
+        // 上位2レベル: (5 - 5) / 10 = 0
+        assert_eq!(order_board.imbalance(2).unwrap(), Decimal::ZERO);
+
+        let (asks, bids) = order_board.cumulative_depth(2);
+        // bestから内側へ積み上げる
+        assert_eq!(asks[0], (Decimal::new(101, 0), Decimal::new(1, 0)));
+        assert_eq!(asks[1], (Decimal::new(102, 0), Decimal::new(5, 0)));
+        assert_eq!(bids[0], (Decimal::new(100, 0), Decimal::new(3, 0)));
+        assert_eq!(bids[1], (Decimal::new(99, 0), Decimal::new(5, 0)));
+    }
+
+    // 列指向エクスポートの検証(sequentialとparが一致)
+    #[test]
+    fn test_to_columns() {
+        let mut order_board = OrderBoard::new(10, 2);
+        order_board.asks.push(Book {
+            price: Decimal::new(10100, 2),
+            size: Decimal::new(1, 0),
+        });
+        order_board.bids.push(Book {
+            price: Decimal::new(10000, 2),
+            size: Decimal::new(2, 0),
+        });
+
+        let columns = order_board.to_columns();
+        assert_eq!(columns.len(), 2);
+        // ask -> bidの順
+        assert_eq!(columns.price[0], Decimal::new(10100, 2));
+        assert_eq!(columns.price[1], Decimal::new(10000, 2));
+        // digits考慮済みの整数price(PriceKey i64)
+        assert_eq!(columns.key[0], 10100);
+        assert_eq!(columns.key[1], 10000);
+        assert!(matches!(columns.side[0], Side::Ask));
+        assert!(matches!(columns.side[1], Side::Bid));
+
+        // 並列版も同じ結果
+        let par = order_board.to_columns_par();
+        assert_eq!(par.price, columns.price);
+        assert_eq!(par.size, columns.size);
+        assert_eq!(par.key, columns.key);
+    }
+
     // 変換ロジックをテスト
     #[test]
     fn test_converter() {